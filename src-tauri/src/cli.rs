@@ -0,0 +1,8 @@
+//! Parses the dataset path, if any, out of argv — shared by first-launch
+//! startup and by the single-instance plugin's forwarded argv.
+
+/// Returns the first non-flag argument after the binary name, treating it
+/// as a dataset path to open (e.g. `quickview /path/to/dataset.vtk`).
+pub fn dataset_path_from_args(args: &[String]) -> Option<String> {
+  args.iter().skip(1).find(|arg| !arg.starts_with('-')).cloned()
+}
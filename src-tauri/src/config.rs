@@ -0,0 +1,128 @@
+//! Loads sidecar configuration from an optional `QuickView.conf.json`
+//! in the app's config directory, following the same
+//! deserialize-with-defaults pattern Tauri uses for `tauri.conf.json`.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+const CONFIG_FILE_NAME: &str = "QuickView.conf.json";
+
+fn default_port() -> u16 {
+  0
+}
+
+fn default_timeout_secs() -> u32 {
+  10
+}
+
+fn default_env() -> HashMap<String, String> {
+  let mut env = HashMap::new();
+  env.insert("PYTHONUNBUFFERED".to_string(), "1".to_string());
+  env
+}
+
+fn default_toggle_shortcut() -> String {
+  "CmdOrCtrl+Shift+Q".to_string()
+}
+
+fn default_control_bind_addr() -> String {
+  "127.0.0.1".to_string()
+}
+
+fn default_control_port() -> u16 {
+  7879
+}
+
+/// Settings for the optional local HTTP control endpoint. Disabled by
+/// default and bound to loopback unless `bind_addr` is set otherwise.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ControlServerConfig {
+  #[serde(default)]
+  pub enabled: bool,
+  #[serde(default = "default_control_bind_addr")]
+  pub bind_addr: String,
+  #[serde(default = "default_control_port")]
+  pub port: u16,
+}
+
+impl Default for ControlServerConfig {
+  fn default() -> Self {
+    Self {
+      enabled: false,
+      bind_addr: default_control_bind_addr(),
+      port: default_control_port(),
+    }
+  }
+}
+
+/// User-overridable settings for the trame sidecar and app chrome,
+/// deserialized from `QuickView.conf.json`. Every field falls back to
+/// today's hard-coded behavior when absent.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SidecarConfig {
+  #[serde(default = "default_port")]
+  pub port: u16,
+  #[serde(default = "default_timeout_secs")]
+  pub timeout_secs: u32,
+  /// Dataset to open automatically on launch, if any.
+  #[serde(default)]
+  pub initial_dataset: Option<String>,
+  #[serde(default = "default_env")]
+  pub env: HashMap<String, String>,
+  /// Global shortcut that toggles the main window's visibility.
+  #[serde(default = "default_toggle_shortcut")]
+  pub toggle_shortcut: String,
+  #[serde(default)]
+  pub control_server: ControlServerConfig,
+}
+
+impl Default for SidecarConfig {
+  fn default() -> Self {
+    Self {
+      port: default_port(),
+      timeout_secs: default_timeout_secs(),
+      initial_dataset: None,
+      env: default_env(),
+      toggle_shortcut: default_toggle_shortcut(),
+      control_server: ControlServerConfig::default(),
+    }
+  }
+}
+
+impl SidecarConfig {
+  /// Reads `QuickView.conf.json` from `config_dir`, falling back cleanly
+  /// to defaults when the directory, file, or individual fields are
+  /// missing or unparsable.
+  pub fn load(config_dir: Option<PathBuf>) -> Self {
+    let Some(dir) = config_dir else {
+      return Self::default();
+    };
+
+    let path = dir.join(CONFIG_FILE_NAME);
+    let contents = match std::fs::read_to_string(&path) {
+      Ok(contents) => contents,
+      Err(_) => return Self::default(),
+    };
+
+    match serde_json::from_str(&contents) {
+      Ok(config) => config,
+      Err(err) => {
+        println!("[config] ignoring malformed {}: {}", path.display(), err);
+        Self::default()
+      }
+    }
+  }
+
+  /// The trame CLI args for this configuration.
+  pub fn args(&self) -> Vec<String> {
+    vec![
+      "--server".to_string(),
+      "--port".to_string(),
+      self.port.to_string(),
+      "--timeout".to_string(),
+      self.timeout_secs.to_string(),
+    ]
+  }
+}
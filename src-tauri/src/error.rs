@@ -0,0 +1,20 @@
+//! App-level error type for startup failures, `Send + Sync` like Tauri
+//! core's own `Error`, so a missing window or a failed sidecar spawn can
+//! be reported to the UI instead of panicking.
+//!
+//! There's no `PortParse` or `Io` variant here: `sidecar::parse_port`
+//! treats a malformed port line as "not ready yet" rather than a fatal
+//! error (see its doc comment), so those failure modes have no path
+//! that would ever construct such a variant. Keeping them around just
+//! to cover the case on paper would trip rustc's `dead_code` lint for
+//! no functional benefit.
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum QuickViewError {
+  #[error("window `{0}` not found")]
+  WindowNotFound(String),
+  #[error("failed to spawn trame sidecar: {0}")]
+  SidecarSpawn(String),
+}
@@ -0,0 +1,118 @@
+//! Optional local HTTP endpoint that lets other tools on the machine
+//! drive the viewer, e.g. `POST /open?path=/data/mesh.vtu` or
+//! `/reset-camera`. Off by default; see `ControlServerConfig`.
+
+use std::thread;
+
+use tauri::{AppHandle, Manager};
+
+use crate::config::ControlServerConfig;
+
+/// Owns the `AppHandle` the blocking request-handling thread needs, since
+/// the handler can't borrow it the way a short-lived closure could.
+struct ControlServer {
+  app_handle: AppHandle,
+}
+
+impl ControlServer {
+  /// Turns a request into a `control-command` window event and a JSON
+  /// status response body. Every route mutates viewer state, so all of
+  /// them require `POST`.
+  fn handle(&self, request: &tiny_http::Request) -> (u16, String) {
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((url.as_str(), ""));
+
+    if !matches!(path, "/open" | "/reset-camera") {
+      return (404, r#"{"status":"error","message":"unknown command"}"#.to_string());
+    }
+
+    if *request.method() != tiny_http::Method::Post {
+      return (405, r#"{"status":"error","message":"use POST"}"#.to_string());
+    }
+
+    let command = match path {
+      "/open" => {
+        let dataset_path = query_param(query, "path").map(percent_decode);
+        match dataset_path {
+          Some(p) => Some(serde_json::json!({ "command": "open", "path": p })),
+          None => None,
+        }
+      }
+      "/reset-camera" => Some(serde_json::json!({ "command": "reset-camera" })),
+      _ => unreachable!("checked above"),
+    };
+
+    let Some(payload) = command else {
+      return (400, r#"{"status":"error","message":"missing path"}"#.to_string());
+    };
+
+    if let Some(main_window) = self.app_handle.get_window("main") {
+      let _ = main_window.emit("control-command", payload);
+      (200, r#"{"status":"ok"}"#.to_string())
+    } else {
+      (500, r#"{"status":"error","message":"no main window"}"#.to_string())
+    }
+  }
+}
+
+fn query_param<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+  query
+    .split('&')
+    .filter_map(|pair| pair.split_once('='))
+    .find(|(k, _)| *k == key)
+    .map(|(_, v)| v)
+}
+
+/// Decodes `%XX` percent-escapes so a path containing spaces or `&`/`=`
+/// survives the query string intact.
+fn percent_decode(input: &str) -> String {
+  let bytes = input.as_bytes();
+  let mut out = Vec::with_capacity(bytes.len());
+  let mut i = 0;
+  while i < bytes.len() {
+    if bytes[i] == b'%' && i + 2 < bytes.len() {
+      let hex = std::str::from_utf8(&bytes[i + 1..i + 3])
+        .ok()
+        .and_then(|h| u8::from_str_radix(h, 16).ok());
+      if let Some(byte) = hex {
+        out.push(byte);
+        i += 3;
+        continue;
+      }
+    }
+    out.push(bytes[i]);
+    i += 1;
+  }
+  String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Spawns the control server on a dedicated OS thread if `config.enabled`.
+/// No-op otherwise.
+pub fn spawn(app_handle: AppHandle, config: &ControlServerConfig) {
+  if !config.enabled {
+    return;
+  }
+
+  let bind_addr = format!("{}:{}", config.bind_addr, config.port);
+  let server = match tiny_http::Server::http(&bind_addr) {
+    Ok(server) => server,
+    Err(err) => {
+      println!("[http_control] failed to bind {}: {}", bind_addr, err);
+      return;
+    }
+  };
+
+  let control = ControlServer { app_handle };
+
+  thread::spawn(move || {
+    for request in server.incoming_requests() {
+      let (status, body) = control.handle(&request);
+      let response = tiny_http::Response::from_string(body)
+        .with_status_code(status)
+        .with_header(
+          tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap(),
+        );
+      let _ = request.respond(response);
+    }
+  });
+}
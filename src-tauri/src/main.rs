@@ -3,62 +3,102 @@
   windows_subsystem = "windows"
 )]
 
-use tauri::api::process::{Command, CommandEvent};
-use tauri::Manager;
-use std::collections::HashMap;
-use std::time::Duration;
-use async_std::task;
+mod cli;
+mod config;
+mod error;
+mod http_control;
+mod sidecar;
+mod state;
+mod tray;
+
+use tauri::{AppHandle, Manager, RunEvent, WindowEvent};
+
+use config::SidecarConfig;
+use error::QuickViewError;
+use state::{SessionState, SidecarHandle};
+
+fn required_window(app_handle: &AppHandle, label: &str) -> Result<tauri::Window, QuickViewError> {
+  app_handle
+    .get_window(label)
+    .ok_or_else(|| QuickViewError::WindowNotFound(label.to_string()))
+}
+
+/// Reports a fatal error (at startup, or when the sidecar supervisor gives
+/// up) to the UI instead of letting it take the app down: prefers the
+/// splashscreen while it is still open and falls back to the main window.
+fn show_fatal_error(app_handle: &AppHandle, err: &QuickViewError) {
+  println!("[startup] fatal error: {}", err);
+  let window = app_handle
+    .get_window("splashscreen")
+    .or_else(|| app_handle.get_window("main"));
+  if let Some(window) = window {
+    let _ = window.emit("startup-error", err.to_string());
+    let _ = window.show();
+  }
+}
 
 fn main() {
   tauri::Builder::default()
+    .manage(SessionState::default())
+    .manage(SidecarHandle::default())
+    .invoke_handler(tauri::generate_handler![state::get_session_url])
+    .plugin(tauri_plugin_single_instance::init(|app_handle, argv, _cwd| {
+      if let Some(main_window) = app_handle.get_window("main") {
+        let _ = main_window.show();
+        let _ = main_window.set_focus();
+      }
+      if let Some(dataset_path) = cli::dataset_path_from_args(&argv) {
+        let _ = app_handle.emit_all("open-dataset", dataset_path);
+      }
+    }))
+    .system_tray(tray::build())
+    .on_system_tray_event(|app_handle, event| tray::handle_event(app_handle, event))
     .setup(|app| {
-      let splashscreen_window = app.get_window("splashscreen").unwrap();
-      let main_window = app.get_window("main").unwrap();
+      let app_handle = app.handle();
+      let startup: Result<(), QuickViewError> = (|| {
+        let splashscreen_window = required_window(&app_handle, "splashscreen")?;
+        let main_window = required_window(&app_handle, "main")?;
+
+        let mut config = SidecarConfig::load(app.path_resolver().app_config_dir());
+        // Overrides any configured `initial_dataset`; `sidecar::supervise`
+        // emits it as an `open-dataset` event once the sidecar reports ready,
+        // and keeps retrying that one dataset path across restarts if the
+        // first spawn attempt dies before reaching readiness, so a
+        // double-clicked/"open with" file isn't lost to a flaky first launch.
+        if let Some(dataset_path) = cli::dataset_path_from_args(&std::env::args().collect::<Vec<_>>()) {
+          config.initial_dataset = Some(dataset_path);
+        }
 
-      let mut env = HashMap::new();
-      env.insert("PYTHONUNBUFFERED".to_string(), "1".to_string());
+        tray::register_toggle_shortcut(&app_handle, &config.toggle_shortcut);
+        http_control::spawn(app_handle.clone(), &config.control_server);
 
-      let (mut rx, _) = Command::new_sidecar("trame")
-        .expect("failed to create sidecar")
-        .args(["--server", "--port", "0", "--timeout", "10"])
-        .envs(env)
-        .spawn()
-        .expect("Failed to spawn server");
+        sidecar::supervise(app_handle.clone(), splashscreen_window, main_window, config);
+
+        Ok(())
+      })();
+
+      // A failed startup is shown to the user, not a reason to crash the
+      // whole app; always report `Ok` to `setup` so the window event loop
+      // still comes up.
+      if let Err(err) = startup {
+        show_fatal_error(&app_handle, &err);
+      }
 
-      tauri::async_runtime::spawn(async move {
-        while let Some(event) = rx.recv().await {
-          match event {
-            CommandEvent::Stdout(line) => {
-              println!("Stdout: {}", line);
-              if line.contains("tauri-server-port=") {
-                let tokens: Vec<&str> = line.split("=").collect();
-                let port_token = tokens[1].to_string();
-                let port = port_token.trim();
-                // println!("window.location.replace(window.location.href + '?sessionURL=ws://localhost:{}/ws')", port);
-                let _ = main_window.eval(&format!("window.location.replace(window.location.href + '?sessionURL=ws://localhost:{}/ws')", port));
-              }
-              if line.contains("tauri-client-ready") {
-                task::sleep(Duration::from_secs(2)).await;
-                splashscreen_window.close().unwrap();
-                main_window.show().unwrap();
-              }
-            },
-            CommandEvent::Stderr(line) => {
-              // Handle stderr output
-              println!("Stderr: {}", line);
-            },
-            CommandEvent::Error(error) => {
-              println!("[Trame error] {}", error);
-            },
-            CommandEvent::Terminated(code) => {
-              println!("[Trame exited] with code {:?}", code);
-            },
-            _ => {},
-          }
-        }
-      });
       Ok(())
     })
-    .run(tauri::generate_context!())
-    .expect("error while running application");
+    .build(tauri::generate_context!())
+    .expect("error while building application")
+    .run(|app_handle, event| {
+      if let RunEvent::WindowEvent {
+        label,
+        event: WindowEvent::CloseRequested { api, .. },
+        ..
+      } = event
+      {
+        if let Some(window) = app_handle.get_window(&label) {
+          api.prevent_close();
+          let _ = window.hide();
+        }
+      }
+    });
 }
\ No newline at end of file
@@ -0,0 +1,222 @@
+//! Supervises the trame Python sidecar process, restarting it with
+//! exponential backoff if it crashes or is killed by its own `--timeout`,
+//! and drives the typed stdout handshake that hands the session URL to the
+//! frontend.
+
+use std::time::{Duration, Instant};
+
+use async_std::task;
+use tauri::api::process::{Command, CommandEvent};
+use tauri::{AppHandle, Manager, Window};
+
+use crate::config::SidecarConfig;
+use crate::error::QuickViewError;
+use crate::state::{SessionState, SidecarHandle};
+
+/// Initial delay before the first restart attempt.
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+/// Upper bound on the backoff delay.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// How long a sidecar must stay alive after emitting `tauri-client-ready`
+/// before we consider it healthy and reset the backoff delay. A sidecar
+/// that dies before this elapses is still counted as a failure, so a
+/// crash-loop right after startup doesn't respawn with no delay forever.
+const HEALTHY_AFTER: Duration = Duration::from_secs(5);
+/// How long the splashscreen lingers after `tauri-client-ready` before
+/// swapping to the main window, independent of the health threshold.
+const SPLASH_LINGER: Duration = Duration::from_secs(2);
+/// Give up when the sidecar fails again after this many consecutive
+/// failed restarts.
+const MAX_RESTARTS: u32 = 8;
+
+const PORT_MARKER: &str = "tauri-server-port=";
+const READY_MARKER: &str = "tauri-client-ready";
+
+/// Spawns the trame sidecar and supervises it for the lifetime of the app,
+/// restarting it with exponential backoff on `Terminated`/`Error` events.
+///
+/// Runs on the Tauri async runtime; call once from `setup`.
+pub fn supervise(app_handle: AppHandle, splashscreen_window: Window, main_window: Window, config: SidecarConfig) {
+  tauri::async_runtime::spawn(async move {
+    let mut backoff = BASE_BACKOFF;
+    let mut restarts = 0u32;
+    let mut last_error: Option<QuickViewError> = None;
+    // Only a launch that actually reaches `tauri-client-ready` should open
+    // the initial dataset; `run_once` takes this by `&mut` and only
+    // `.take()`s it at the point it emits `open-dataset`, so a spawn that
+    // crashes before reaching `tauri-client-ready` leaves it in place for
+    // the retry.
+    let mut pending_dataset = config.initial_dataset.clone();
+
+    loop {
+      let result = run_once(
+        &app_handle,
+        &splashscreen_window,
+        &main_window,
+        &config,
+        &mut pending_dataset,
+      )
+      .await;
+
+      match result {
+        SidecarOutcome::HealthyExit => {
+          // The sidecar reported healthy before exiting; treat this as a
+          // fresh start rather than a crash.
+          restarts = 0;
+          backoff = BASE_BACKOFF;
+          last_error = None;
+        }
+        SidecarOutcome::CrashedBeforeHealthy(err) => {
+          restarts += 1;
+          if err.is_some() {
+            last_error = err;
+          }
+          if restarts > MAX_RESTARTS {
+            let fatal_error = last_error.take().unwrap_or_else(|| {
+              QuickViewError::SidecarSpawn(format!(
+                "trame exited {} times in a row without becoming healthy",
+                restarts
+              ))
+            });
+            crate::show_fatal_error(&app_handle, &fatal_error);
+            break;
+          }
+          let _ = main_window.emit(
+            "server-restarting",
+            format!("restart {}/{} in {:?}", restarts, MAX_RESTARTS, backoff),
+          );
+          task::sleep(backoff).await;
+          backoff = std::cmp::min(backoff * 2, MAX_BACKOFF);
+        }
+      }
+    }
+  });
+}
+
+enum SidecarOutcome {
+  /// The sidecar became healthy (stayed alive for `HEALTHY_AFTER` after
+  /// emitting `tauri-client-ready`) before this run ended.
+  HealthyExit,
+  /// The sidecar exited or errored before ever becoming healthy, carrying
+  /// the spawn error when that's what caused it.
+  CrashedBeforeHealthy(Option<QuickViewError>),
+}
+
+/// Tracks progress through the sidecar's stdout handshake: it must report
+/// a port before it can report readiness.
+enum Handshake {
+  AwaitingPort,
+  AwaitingReady { port: u16 },
+}
+
+/// Spawns one instance of the sidecar and drives its event stream until it
+/// terminates or errors, returning whether it ever became healthy.
+async fn run_once(
+  app_handle: &AppHandle,
+  splashscreen_window: &Window,
+  main_window: &Window,
+  config: &SidecarConfig,
+  pending_dataset: &mut Option<String>,
+) -> SidecarOutcome {
+  let spawn_result = Command::new_sidecar("trame")
+    .and_then(|cmd| cmd.args(config.args()).envs(config.env.clone()).spawn());
+
+  let (mut rx, child) = match spawn_result {
+    Ok(pair) => pair,
+    Err(err) => {
+      let err = QuickViewError::SidecarSpawn(err.to_string());
+      println!("[sidecar] {}", err);
+      return SidecarOutcome::CrashedBeforeHealthy(Some(err));
+    }
+  };
+
+  if let Some(handle) = app_handle.try_state::<SidecarHandle>() {
+    handle.set(child);
+  }
+
+  let mut handshake = Handshake::AwaitingPort;
+  let mut ready_since: Option<Instant> = None;
+
+  while let Some(event) = rx.recv().await {
+    match event {
+      CommandEvent::Stdout(line) => {
+        println!("Stdout: {}", line);
+        if ready_since.is_none() && matches!(handshake, Handshake::AwaitingReady { .. }) && line.contains(READY_MARKER) {
+          ready_since = Some(Instant::now());
+          if let Some(dataset_path) = pending_dataset.take() {
+            let _ = main_window.emit("open-dataset", dataset_path);
+          }
+        }
+        handshake = advance_handshake(app_handle, splashscreen_window, main_window, handshake, &line).await;
+      }
+      CommandEvent::Stderr(line) => {
+        println!("Stderr: {}", line);
+      }
+      CommandEvent::Error(error) => {
+        println!("[Trame error] {}", error);
+        break;
+      }
+      CommandEvent::Terminated(code) => {
+        println!("[Trame exited] with code {:?}", code);
+        break;
+      }
+      _ => {}
+    }
+  }
+
+  if let Some(handle) = app_handle.try_state::<SidecarHandle>() {
+    handle.clear();
+  }
+
+  let stayed_healthy = ready_since.is_some_and(|since| since.elapsed() >= HEALTHY_AFTER);
+  if stayed_healthy {
+    SidecarOutcome::HealthyExit
+  } else {
+    SidecarOutcome::CrashedBeforeHealthy(None)
+  }
+}
+
+/// Recognizes the sidecar's lifecycle markers defensively: malformed or
+/// out-of-order lines are ignored rather than panicking.
+async fn advance_handshake(
+  app_handle: &AppHandle,
+  splashscreen_window: &Window,
+  main_window: &Window,
+  handshake: Handshake,
+  line: &str,
+) -> Handshake {
+  match handshake {
+    Handshake::AwaitingPort => {
+      let Some(port) = parse_port(line) else {
+        return Handshake::AwaitingPort;
+      };
+      let session_url = format!("ws://localhost:{}/ws", port);
+      if let Some(state) = app_handle.try_state::<SessionState>() {
+        state.set_session_url(session_url.clone());
+      }
+      let _ = main_window.emit("server-ready", session_url);
+      Handshake::AwaitingReady { port }
+    }
+    Handshake::AwaitingReady { port } => {
+      if line.contains(READY_MARKER) {
+        println!("[sidecar] trame ready on port {}", port);
+        task::sleep(SPLASH_LINGER).await;
+        let _ = splashscreen_window.close();
+        let _ = main_window.show();
+      }
+      Handshake::AwaitingReady { port }
+    }
+  }
+}
+
+/// Parses a `tauri-server-port=<u16>` line, ignoring anything that doesn't
+/// match the expected shape. Takes the first whitespace-separated token
+/// after the marker rather than
+/// requiring the whole remainder to be the port, so trailing log
+/// decoration (`tauri-server-port=12345 ready`) doesn't defeat parsing.
+fn parse_port(line: &str) -> Option<u16> {
+  let value = line.strip_prefix(PORT_MARKER).or_else(|| {
+    line.find(PORT_MARKER).map(|idx| &line[idx + PORT_MARKER.len()..])
+  })?;
+  value.trim().split_whitespace().next()?.parse::<u16>().ok()
+}
@@ -0,0 +1,57 @@
+//! Shared application state exposed to the frontend over Tauri IPC.
+
+use std::sync::{Mutex, RwLock};
+
+use tauri::api::process::CommandChild;
+
+/// Holds the trame session URL once the sidecar handshake completes.
+///
+/// Managed via `app.manage(SessionState::default())` and read/written by
+/// both the sidecar supervisor and the `get_session_url` command.
+#[derive(Default)]
+pub struct SessionState {
+  session_url: RwLock<Option<String>>,
+}
+
+impl SessionState {
+  pub fn set_session_url(&self, url: String) {
+    *self.session_url.write().unwrap() = Some(url);
+  }
+
+  pub fn session_url(&self) -> Option<String> {
+    self.session_url.read().unwrap().clone()
+  }
+}
+
+/// Returns the current trame session URL over IPC, if the sidecar
+/// handshake has completed.
+#[tauri::command]
+pub fn get_session_url(state: tauri::State<SessionState>) -> Option<String> {
+  state.session_url()
+}
+
+/// Holds the currently-running sidecar child process, if any, so that
+/// external actions (e.g. the tray's "Restart server" item) can kill it
+/// and let the supervisor's restart loop bring up a fresh one.
+#[derive(Default)]
+pub struct SidecarHandle {
+  child: Mutex<Option<CommandChild>>,
+}
+
+impl SidecarHandle {
+  pub fn set(&self, child: CommandChild) {
+    *self.child.lock().unwrap() = Some(child);
+  }
+
+  pub fn clear(&self) {
+    *self.child.lock().unwrap() = None;
+  }
+
+  /// Kills the current sidecar process, if one is running. The supervisor
+  /// will observe the resulting `Terminated` event and respawn it.
+  pub fn kill_current(&self) {
+    if let Some(child) = self.child.lock().unwrap().take() {
+      let _ = child.kill();
+    }
+  }
+}
@@ -0,0 +1,70 @@
+//! System tray menu (Show/Hide, Restart server, Quit) and the global
+//! shortcut that toggles the main window's visibility.
+
+use tauri::{
+  AppHandle, GlobalShortcutManager, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu,
+  SystemTrayMenuItem,
+};
+
+use crate::state::SidecarHandle;
+
+const SHOW_HIDE_ID: &str = "show_hide";
+const RESTART_ID: &str = "restart_server";
+const QUIT_ID: &str = "quit";
+
+/// Builds the tray menu. Wire up with `.system_tray(tray::build())` and
+/// `.on_system_tray_event(tray::handle_event)`.
+pub fn build() -> SystemTray {
+  let menu = SystemTrayMenu::new()
+    .add_item(tauri::CustomMenuItem::new(SHOW_HIDE_ID, "Show/Hide"))
+    .add_native_item(SystemTrayMenuItem::Separator)
+    .add_item(tauri::CustomMenuItem::new(RESTART_ID, "Restart server"))
+    .add_native_item(SystemTrayMenuItem::Separator)
+    .add_item(tauri::CustomMenuItem::new(QUIT_ID, "Quit"));
+
+  SystemTray::new().with_menu(menu)
+}
+
+pub fn handle_event(app_handle: &AppHandle, event: SystemTrayEvent) {
+  let SystemTrayEvent::MenuItemClick { id, .. } = event else {
+    return;
+  };
+
+  match id.as_str() {
+    SHOW_HIDE_ID => toggle_main_window(app_handle),
+    RESTART_ID => {
+      if let Some(handle) = app_handle.try_state::<SidecarHandle>() {
+        handle.kill_current();
+      }
+    }
+    QUIT_ID => app_handle.exit(0),
+    _ => {}
+  }
+}
+
+/// Registers `shortcut` as a global hotkey that toggles the main window's
+/// visibility. Logs and no-ops if the shortcut can't be registered (e.g.
+/// it's already claimed by another application).
+pub fn register_toggle_shortcut(app_handle: &AppHandle, shortcut: &str) {
+  let handle = app_handle.clone();
+  let mut manager = app_handle.global_shortcut_manager();
+  let result = manager.register(shortcut, move || {
+    toggle_main_window(&handle);
+  });
+  if let Err(err) = result {
+    println!("[tray] failed to register global shortcut {}: {}", shortcut, err);
+  }
+}
+
+fn toggle_main_window(app_handle: &AppHandle) {
+  let Some(main_window) = app_handle.get_window("main") else {
+    return;
+  };
+  let is_visible = main_window.is_visible().unwrap_or(false);
+  if is_visible {
+    let _ = main_window.hide();
+  } else {
+    let _ = main_window.show();
+    let _ = main_window.set_focus();
+  }
+}